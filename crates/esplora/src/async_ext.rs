@@ -26,6 +26,10 @@ pub trait EsploraAsyncExt {
     /// `stop_gap` script pubkeys with no associated transactions. `parallel_requests` specifies
     /// the maximum number of HTTP requests to make in parallel.
     ///
+    /// If `request` contains a chain tip which does not share a genesis block with Esplora (i.e.
+    /// Esplora serves a different network), [`esplora_client::Error::HeaderHashNotFound`] is
+    /// returned.
+    ///
     /// Refer to [crate-level docs](crate) for more.
     async fn full_scan<K: Ord + Clone + Send, R: Into<FullScanRequest<K>> + Send>(
         &self,
@@ -40,6 +44,10 @@ pub trait EsploraAsyncExt {
     /// [`SyncRequest`]). `parallel_requests` specifies the maximum number of HTTP requests to make
     /// in parallel.
     ///
+    /// If `request` contains a chain tip which does not share a genesis block with Esplora (i.e.
+    /// Esplora serves a different network), [`esplora_client::Error::HeaderHashNotFound`] is
+    /// returned.
+    ///
     /// Refer to [crate-level docs](crate) for more.
     async fn sync<I: Send, R: Into<SyncRequest<I>> + Send>(
         &self,
@@ -205,6 +213,9 @@ async fn fetch_block(
 ///
 /// We want to have a corresponding checkpoint per anchor height. However, checkpoints fetched
 /// should not surpass `latest_blocks`.
+///
+/// Errors with [`esplora_client::Error::HeaderHashNotFound`] (containing the local genesis hash) if
+/// the remote does not share a genesis block with `local_tip`.
 async fn chain_update(
     client: &esplora_client::AsyncClient,
     latest_blocks: &BTreeMap<u32, BlockHash>,
@@ -232,7 +243,20 @@ async fn chain_update(
         }
     }
 
-    let mut tip = point_of_agreement.expect("remote esplora should have same genesis block");
+    // The local genesis block is the last checkpoint we compare against, so no point of
+    // agreement means the remote is serving a different chain (e.g. the wrong network).
+    let mut tip = match point_of_agreement {
+        Some(tip) => tip,
+        None => {
+            let genesis = local_tip
+                .iter()
+                .last()
+                .expect("must have atleast one entry");
+            return Err(Box::new(esplora_client::Error::HeaderHashNotFound(
+                genesis.hash(),
+            )));
+        }
+    };
 
     tip = tip
         .extend(conflicts.into_iter().rev())
@@ -280,6 +304,8 @@ async fn fetch_txs_with_keychain_spks<I: Iterator<Item = Indexed<ScriptBuf>> + S
 ) -> Result<(TxUpdate<ConfirmationBlockTime>, Option<u32>), Error> {
     type TxsOfSpkIndex = (u32, Vec<esplora_client::Tx>);
 
+    // A `stop_gap` of 0 is treated as a `stop_gap` of 1 (see crate-level docs).
+    let stop_gap = stop_gap.max(1);
     let mut update = TxUpdate::<ConfirmationBlockTime>::default();
     let mut last_index = Option::<u32>::None;
     let mut last_active_index = Option::<u32>::None;
@@ -24,6 +24,10 @@ pub trait EsploraExt {
     /// `stop_gap` script pubkeys with no associated transactions. `parallel_requests` specifies
     /// the maximum number of HTTP requests to make in parallel.
     ///
+    /// If `request` contains a chain tip which does not share a genesis block with Esplora (i.e.
+    /// Esplora serves a different network), [`esplora_client::Error::HeaderHashNotFound`] is
+    /// returned.
+    ///
     /// Refer to [crate-level docs](crate) for more.
     fn full_scan<K: Ord + Clone, R: Into<FullScanRequest<K>>>(
         &self,
@@ -38,6 +42,10 @@ pub trait EsploraExt {
     /// [`SyncRequest`]). `parallel_requests` specifies the maximum number of HTTP requests to make
     /// in parallel.
     ///
+    /// If `request` contains a chain tip which does not share a genesis block with Esplora (i.e.
+    /// Esplora serves a different network), [`esplora_client::Error::HeaderHashNotFound`] is
+    /// returned.
+    ///
     /// Refer to [crate-level docs](crate) for more.
     fn sync<I: 'static, R: Into<SyncRequest<I>>>(
         &self,
@@ -195,6 +203,9 @@ fn fetch_block(
 ///
 /// We want to have a corresponding checkpoint per anchor height. However, checkpoints fetched
 /// should not surpass `latest_blocks`.
+///
+/// Errors with [`esplora_client::Error::HeaderHashNotFound`] (containing the local genesis hash) if
+/// the remote does not share a genesis block with `local_tip`.
 fn chain_update(
     client: &esplora_client::BlockingClient,
     latest_blocks: &BTreeMap<u32, BlockHash>,
@@ -222,7 +233,20 @@ fn chain_update(
         }
     }
 
-    let mut tip = point_of_agreement.expect("remote esplora should have same genesis block");
+    // The local genesis block is the last checkpoint we compare against, so no point of
+    // agreement means the remote is serving a different chain (e.g. the wrong network).
+    let mut tip = match point_of_agreement {
+        Some(tip) => tip,
+        None => {
+            let genesis = local_tip
+                .iter()
+                .last()
+                .expect("must have atleast one entry");
+            return Err(Box::new(esplora_client::Error::HeaderHashNotFound(
+                genesis.hash(),
+            )));
+        }
+    };
 
     tip = tip
         .extend(conflicts.into_iter().rev())
@@ -257,6 +281,8 @@ fn fetch_txs_with_keychain_spks<I: Iterator<Item = Indexed<ScriptBuf>>>(
 ) -> Result<(TxUpdate<ConfirmationBlockTime>, Option<u32>), Error> {
     type TxsOfSpkIndex = (u32, Vec<esplora_client::Tx>);
 
+    // A `stop_gap` of 0 is treated as a `stop_gap` of 1 (see crate-level docs).
+    let stop_gap = stop_gap.max(1);
     let mut update = TxUpdate::<ConfirmationBlockTime>::default();
     let mut last_index = Option::<u32>::None;
     let mut last_active_index = Option::<u32>::None;
@@ -856,4 +882,28 @@ mod test {
 
         Ok(())
     }
+
+    /// A chain tip with a genesis block unknown to Esplora must error rather than panic.
+    #[test]
+    fn chain_update_with_mismatched_genesis() -> anyhow::Result<()> {
+        let env = TestEnv::new()?;
+        let base_url = format!("http://{}", &env.electrsd.esplora_url.clone().unwrap());
+        let client = Builder::new(base_url.as_str()).build_blocking();
+
+        let genesis_hash: BlockHash = h!("other_genesis");
+        let chain = local_chain![(0, genesis_hash)];
+        let err = chain_update(
+            &client,
+            &fetch_latest_blocks(&client)?,
+            &chain.tip(),
+            &BTreeSet::new(),
+        )
+        .expect_err("genesis mismatch must error");
+        assert!(matches!(
+            *err,
+            esplora_client::Error::HeaderHashNotFound(hash) if hash == genesis_hash
+        ));
+
+        Ok(())
+    }
 }
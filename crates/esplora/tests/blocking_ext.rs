@@ -4,6 +4,7 @@ use bdk_esplora::EsploraExt;
 use esplora_client::{self, Builder};
 use std::collections::{BTreeSet, HashSet};
 use std::str::FromStr;
+use std::sync::{Arc, Mutex};
 use std::thread::sleep;
 use std::time::Duration;
 
@@ -245,5 +246,21 @@ pub fn test_update_tx_graph_stop_gap() -> anyhow::Result<()> {
     assert!(txs.contains(&txid_4th_addr) && txs.contains(&txid_last_addr));
     assert_eq!(full_scan_update.last_active_indices[&0], 9);
 
+    // A stop_gap of 0 is treated as a stop_gap of 1: starting the scan at the 4th address, one
+    // more (unused) address is scanned after the active one.
+    for stop_gap in [0, 1] {
+        let scanned = Arc::new(Mutex::new(Vec::new()));
+        let request = FullScanRequest::builder()
+            .chain_tip(cp_tip.clone())
+            .spks_for_keychain(0, spks[3..].to_vec())
+            .inspect({
+                let scanned = scanned.clone();
+                move |_, i, _| scanned.lock().unwrap().push(i)
+            });
+        let full_scan_update = client.full_scan(request, stop_gap, 1)?;
+        assert_eq!(full_scan_update.last_active_indices[&0], 3);
+        assert_eq!(*scanned.lock().unwrap(), [3, 4]);
+    }
+
     Ok(())
 }